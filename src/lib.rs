@@ -1,6 +1,6 @@
 //! An attribute macro to stipulate bounds.
 //!
-//! The attribute applies bounds to `struct`s, `enum`s, `union`s, `trait`s, `fn`s, associated `type`s, and `impl` blocks.
+//! The attribute applies bounds to `struct`s, `enum`s, `union`s, `trait`s, `fn`s, associated `type`s, and `impl` blocks, including the `fn`s and associated `type`s defined inside them.
 //!
 //! ```rust
 //! use attr_bounds::bounds;
@@ -51,8 +51,8 @@ use syn::{
     parse::{discouraged::Speculative, Parse},
     parse_macro_input,
     punctuated::Punctuated,
-    ItemEnum, ItemFn, ItemImpl, ItemStruct, ItemTrait, ItemType, ItemUnion, Signature, Token,
-    TraitItemFn, TraitItemType, WhereClause, WherePredicate,
+    ImplItemConst, ImplItemFn, ImplItemType, ItemEnum, ItemFn, ItemImpl, ItemStruct, ItemTrait,
+    ItemType, ItemUnion, Signature, Token, TraitItemFn, TraitItemType, WhereClause, WherePredicate,
 };
 
 enum Item {
@@ -65,10 +65,13 @@ enum Item {
     Union(ItemUnion),
     AssocType(TraitItemType),
     FnDecl(TraitItemFn),
+    ImplFn(ImplItemFn),
+    ImplType(ImplItemType),
+    ImplConst(ImplItemConst),
 }
 
 impl Item {
-    fn make_where_clause(&mut self) -> &mut WhereClause {
+    fn make_where_clause(&mut self) -> syn::Result<&mut WhereClause> {
         let generics = match self {
             Item::Enum(ItemEnum { generics, .. })
             | Item::Fn(ItemFn {
@@ -84,9 +87,20 @@ impl Item {
             | Item::FnDecl(TraitItemFn {
                 sig: Signature { generics, .. },
                 ..
-            }) => generics,
+            })
+            | Item::ImplFn(ImplItemFn {
+                sig: Signature { generics, .. },
+                ..
+            })
+            | Item::ImplType(ImplItemType { generics, .. }) => generics,
+            Item::ImplConst(item) => {
+                return Err(syn::Error::new_spanned(
+                    item,
+                    "`#[bounds]` cannot be applied to an associated const, since it has no generics to attach a where clause to.",
+                ))
+            }
         };
-        generics.make_where_clause()
+        Ok(generics.make_where_clause())
     }
 }
 
@@ -110,7 +124,8 @@ impl Parse for Item {
             return Ok(item);
         }
 
-        if let Ok(item) = input
+        let fork = input.fork();
+        if let Ok(item) = fork
             .parse::<syn::TraitItem>()
             .map_or(Err(()), |item| match item {
                 syn::TraitItem::Fn(item) => Ok(Item::FnDecl(item)),
@@ -118,6 +133,21 @@ impl Parse for Item {
                 _ => Err(()),
             })
         {
+            input.advance_to(&fork);
+            return Ok(item);
+        }
+
+        let fork = input.fork();
+        if let Ok(item) = fork
+            .parse::<syn::ImplItem>()
+            .map_or(Err(()), |item| match item {
+                syn::ImplItem::Fn(item) => Ok(Item::ImplFn(item)),
+                syn::ImplItem::Type(item) => Ok(Item::ImplType(item)),
+                syn::ImplItem::Const(item) => Ok(Item::ImplConst(item)),
+                _ => Err(()),
+            })
+        {
+            input.advance_to(&fork);
             return Ok(item);
         }
 
@@ -137,6 +167,9 @@ impl ToTokens for Item {
             Item::Union(item) => item.to_tokens(tokens),
             Item::AssocType(item) => item.to_tokens(tokens),
             Item::FnDecl(item) => item.to_tokens(tokens),
+            Item::ImplFn(item) => item.to_tokens(tokens),
+            Item::ImplType(item) => item.to_tokens(tokens),
+            Item::ImplConst(item) => item.to_tokens(tokens),
         }
     }
 }
@@ -169,11 +202,13 @@ pub fn bounds(
     let attr = parse_macro_input!(attr with parser);
 
     match syn::parse::<Item>(input) {
-        Ok(mut item) => {
-            let where_clause = item.make_where_clause();
-            where_clause.predicates.extend(attr);
-            item.into_token_stream().into()
-        }
+        Ok(mut item) => match item.make_where_clause() {
+            Ok(where_clause) => {
+                where_clause.predicates.extend(attr);
+                item.into_token_stream().into()
+            }
+            Err(err) => err.to_compile_error().into(),
+        },
         Err(_) => {
             // Using the compile_error!() macro to highlight the attribute in reporting an error.
             quote! {
@@ -53,6 +53,20 @@ fn assoc_fn_bounds() {
     <()>::display(42);
 }
 
+#[test]
+fn impl_pub_fn_bounds() {
+    struct Greeter;
+
+    impl Greeter {
+        #[bounds(T: std::fmt::Display)]
+        pub fn greet<T>(var: T) -> String {
+            format!("{var}")
+        }
+    }
+
+    assert_eq!(Greeter::greet(42), "42");
+}
+
 #[test]
 fn impl_bounds() {
     use std::{fmt::Display, ops::Add};
@@ -0,0 +1,12 @@
+use attr_bounds::bounds;
+
+struct Foo;
+
+impl Foo {
+    #[bounds(Foo: Sized)]
+    const VALUE: Foo = Foo;
+}
+
+fn main() {
+    let _ = Foo::VALUE;
+}